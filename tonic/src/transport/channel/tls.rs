@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use tokio_rustls::rustls;
+
+use crate::transport::{service::tls::TlsConnector, Certificate, Identity};
+
+/// Configures TLS settings for clients, see
+/// [`Endpoint::tls_config`](super::Endpoint::tls_config).
+#[derive(Clone, Default)]
+pub struct ClientTlsConfig {
+    domain: Option<String>,
+    ca_certificate: Option<Certificate>,
+    identity: Option<Identity>,
+    assume_http2: bool,
+    custom_cert_verifier: Option<Arc<dyn rustls::client::danger::ServerCertVerifier>>,
+    crypto_provider: Option<Arc<rustls::crypto::CryptoProvider>>,
+    protocol_versions: Option<&'static [&'static rustls::SupportedProtocolVersion]>,
+    enable_key_log: bool,
+}
+
+impl ClientTlsConfig {
+    /// Creates a new `ClientTlsConfig`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the domain name against which the server's certificate is
+    /// validated. Defaults to the host portion of the endpoint's URI.
+    pub fn domain_name(self, domain_name: impl Into<String>) -> Self {
+        ClientTlsConfig {
+            domain: Some(domain_name.into()),
+            ..self
+        }
+    }
+
+    /// Sets the CA certificate(s) used to verify the server's certificate,
+    /// in place of the platform's root store.
+    pub fn ca_certificate(self, ca_certificate: Certificate) -> Self {
+        ClientTlsConfig {
+            ca_certificate: Some(ca_certificate),
+            ..self
+        }
+    }
+
+    /// Sets the client certificate/private key presented during mTLS.
+    pub fn identity(self, identity: Identity) -> Self {
+        ClientTlsConfig {
+            identity: Some(identity),
+            ..self
+        }
+    }
+
+    /// When set, a handshake that doesn't negotiate HTTP/2 via ALPN is
+    /// still accepted rather than rejected.
+    pub fn assume_http2(self, assume_http2: bool) -> Self {
+        ClientTlsConfig {
+            assume_http2,
+            ..self
+        }
+    }
+
+    /// Replaces webpki root validation entirely with a caller-supplied
+    /// verifier. This is an escape hatch for certificate pinning, SPIFFE-style
+    /// SAN URI verification, or trusting self-signed peers in tests - none of
+    /// rustls's usual chain-of-trust guarantees apply once this is set.
+    ///
+    /// Mutually exclusive with [`ClientTlsConfig::ca_certificate`]: supplying
+    /// both is a configuration error caught when the connector is built,
+    /// rather than during the handshake.
+    pub fn custom_cert_verifier(
+        self,
+        verifier: Arc<dyn rustls::client::danger::ServerCertVerifier>,
+    ) -> Self {
+        ClientTlsConfig {
+            custom_cert_verifier: Some(verifier),
+            ..self
+        }
+    }
+
+    /// Sets the `CryptoProvider` (e.g. `ring` or `aws-lc-rs`) used to build
+    /// the underlying `ClientConfig`, rather than relying on whichever one
+    /// rustls' process-default happens to be. This matters once a
+    /// dependency pulls in more than one provider crate, which otherwise
+    /// leaves rustls with no unambiguous default to fall back on.
+    ///
+    /// Selecting `ring` vs. `aws-lc-rs` is a matter of which provider crate
+    /// the binary links and constructs here
+    /// (`rustls::crypto::ring::default_provider()` or
+    /// `rustls::crypto::aws_lc_rs::default_provider()`) - this crate does
+    /// not re-export either provider itself.
+    pub fn crypto_provider(self, crypto_provider: Arc<rustls::crypto::CryptoProvider>) -> Self {
+        ClientTlsConfig {
+            crypto_provider: Some(crypto_provider),
+            ..self
+        }
+    }
+
+    /// Restricts the negotiated TLS protocol version(s) to `versions` (e.g.
+    /// `&[&rustls::version::TLS13]` to require TLS 1.3 for compliance, or
+    /// `&[&rustls::version::TLS12, &rustls::version::TLS13]` to still allow
+    /// legacy TLS 1.2 peers). Defaults to rustls' safe default versions.
+    pub fn protocol_versions(
+        self,
+        versions: &'static [&'static rustls::SupportedProtocolVersion],
+    ) -> Self {
+        ClientTlsConfig {
+            protocol_versions: Some(versions),
+            ..self
+        }
+    }
+
+    /// Enables logging the TLS session's traffic secrets via rustls'
+    /// [`KeyLogFile`](rustls::KeyLogFile), honoring the `SSLKEYLOGFILE`
+    /// environment variable so captured sessions can be decrypted in e.g.
+    /// Wireshark. Intended for development only.
+    pub fn enable_key_log(self) -> Self {
+        ClientTlsConfig {
+            enable_key_log: true,
+            ..self
+        }
+    }
+
+    /// Builds the [`TlsConnector`] this configuration describes, consumed by
+    /// [`Endpoint::connect`](super::Endpoint::connect) when it dials the
+    /// server. `default_domain` is used when [`ClientTlsConfig::domain_name`]
+    /// wasn't called, typically the host portion of the endpoint's URI.
+    pub(crate) fn into_tls_connector(
+        self,
+        default_domain: &str,
+    ) -> Result<TlsConnector, crate::Error> {
+        let domain = self.domain.as_deref().unwrap_or(default_domain);
+
+        TlsConnector::new(
+            self.ca_certificate,
+            self.identity,
+            domain,
+            self.assume_http2,
+            self.custom_cert_verifier,
+            self.crypto_provider,
+            self.protocol_versions,
+            self.enable_key_log,
+        )
+    }
+}
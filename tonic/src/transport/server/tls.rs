@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio_rustls::rustls;
+
+use crate::transport::{
+    service::tls::{CrlRevocationPolicy, ServerCertSource, TlsAcceptor, TlsError},
+    Certificate, Identity,
+};
+
+/// Configures TLS settings for servers, see [`Server::tls_config`](super::Server::tls_config).
+#[derive(Clone, Default)]
+pub struct ServerTlsConfig {
+    identity: Option<Identity>,
+    sni_identities: HashMap<String, Identity>,
+    client_ca_root: Option<Certificate>,
+    client_auth_optional: bool,
+    crl_pems: Vec<Vec<u8>>,
+    crl_revocation_policy: CrlRevocationPolicy,
+    crypto_provider: Option<Arc<rustls::crypto::CryptoProvider>>,
+    protocol_versions: Option<&'static [&'static rustls::SupportedProtocolVersion]>,
+    enable_key_log: bool,
+}
+
+impl ServerTlsConfig {
+    /// Creates a new `ServerTlsConfig`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the certificate/private key the server presents to connecting
+    /// clients. When [`ServerTlsConfig::identity_for_hostname`] is also
+    /// used, this becomes the default identity served to clients whose SNI
+    /// hostname doesn't match any of those.
+    pub fn identity(self, identity: Identity) -> Self {
+        ServerTlsConfig {
+            identity: Some(identity),
+            ..self
+        }
+    }
+
+    /// Presents `identity` to clients that request `hostname` via SNI,
+    /// matched exactly first, then as a `*.example.com` wildcard. May be
+    /// called more than once to serve multiple hostnames from one listener.
+    /// Clients whose SNI hostname doesn't match anything configured here
+    /// fall back to [`ServerTlsConfig::identity`] if set, or have the
+    /// handshake aborted otherwise.
+    pub fn identity_for_hostname(
+        mut self,
+        hostname: impl Into<String>,
+        identity: Identity,
+    ) -> Self {
+        self.sni_identities.insert(hostname.into(), identity);
+        self
+    }
+
+    /// Sets the CA certificate(s) to use to verify incoming client
+    /// certificates, enabling mTLS. Without this, client certificates are
+    /// not requested.
+    pub fn client_ca_root(self, ca_cert: Certificate) -> Self {
+        ServerTlsConfig {
+            client_ca_root: Some(ca_cert),
+            ..self
+        }
+    }
+
+    /// When `client_ca_root` is set, controls whether a client that doesn't
+    /// present a certificate is still accepted (`true`) or rejected during
+    /// the handshake (`false`, the default).
+    pub fn client_auth_optional(self, optional: bool) -> Self {
+        ServerTlsConfig {
+            client_auth_optional: optional,
+            ..self
+        }
+    }
+
+    /// Adds a PEM-encoded Certificate Revocation List, checked against
+    /// client certificates during mTLS verification. May be called more
+    /// than once to supply CRLs from multiple issuers.
+    pub fn client_cert_revocation_list(mut self, crl_pem: impl AsRef<[u8]>) -> Self {
+        self.crl_pems.push(crl_pem.as_ref().to_vec());
+        self
+    }
+
+    /// Restricts revocation checking to the end-entity certificate only,
+    /// rather than the full chain up to the root.
+    pub fn crl_only_check_end_entity(mut self, only_check_end_entity: bool) -> Self {
+        self.crl_revocation_policy.only_check_end_entity = only_check_end_entity;
+        self
+    }
+
+    /// Treats an unknown revocation status (e.g. a CRL that doesn't cover
+    /// an intermediate) as allowed rather than as a verification error.
+    pub fn crl_allow_unknown_status(mut self, allow_unknown_status: bool) -> Self {
+        self.crl_revocation_policy.allow_unknown_status = allow_unknown_status;
+        self
+    }
+
+    /// Sets the `CryptoProvider` (e.g. `ring` or `aws-lc-rs`) used to build
+    /// the underlying `ServerConfig`, rather than relying on whichever one
+    /// rustls' process-default happens to be. This matters once a
+    /// dependency pulls in more than one provider crate, which otherwise
+    /// leaves rustls with no unambiguous default to fall back on.
+    ///
+    /// Selecting `ring` vs. `aws-lc-rs` is a matter of which provider crate
+    /// the binary links and constructs here
+    /// (`rustls::crypto::ring::default_provider()` or
+    /// `rustls::crypto::aws_lc_rs::default_provider()`) - this crate does
+    /// not re-export either provider itself.
+    pub fn crypto_provider(self, crypto_provider: Arc<rustls::crypto::CryptoProvider>) -> Self {
+        ServerTlsConfig {
+            crypto_provider: Some(crypto_provider),
+            ..self
+        }
+    }
+
+    /// Restricts the negotiated TLS protocol version(s) to `versions` (e.g.
+    /// `&[&rustls::version::TLS13]` to require TLS 1.3 for compliance, or
+    /// `&[&rustls::version::TLS12, &rustls::version::TLS13]` to still allow
+    /// legacy TLS 1.2 peers). Defaults to rustls' safe default versions.
+    pub fn protocol_versions(
+        self,
+        versions: &'static [&'static rustls::SupportedProtocolVersion],
+    ) -> Self {
+        ServerTlsConfig {
+            protocol_versions: Some(versions),
+            ..self
+        }
+    }
+
+    /// Enables logging the TLS session's traffic secrets via rustls'
+    /// [`KeyLogFile`](rustls::KeyLogFile), honoring the `SSLKEYLOGFILE`
+    /// environment variable so captured sessions can be decrypted in e.g.
+    /// Wireshark. Intended for development only.
+    pub fn enable_key_log(self) -> Self {
+        ServerTlsConfig {
+            enable_key_log: true,
+            ..self
+        }
+    }
+
+    /// Builds the [`TlsAcceptor`] this configuration describes, consumed by
+    /// [`Server::tls_config`](super::Server::tls_config) when it accepts
+    /// incoming connections.
+    pub(crate) fn into_tls_acceptor(self) -> Result<TlsAcceptor, crate::Error> {
+        let cert_source = if self.sni_identities.is_empty() {
+            let identity = self
+                .identity
+                .ok_or_else(|| Box::new(TlsError::MissingIdentity) as crate::Error)?;
+            ServerCertSource::Single(identity)
+        } else {
+            ServerCertSource::Sni {
+                certs: self.sni_identities,
+                default_identity: self.identity,
+            }
+        };
+
+        TlsAcceptor::new(
+            cert_source,
+            self.client_ca_root,
+            self.client_auth_optional,
+            self.crl_pems,
+            self.crl_revocation_policy,
+            self.crypto_provider,
+            self.protocol_versions,
+            self.enable_key_log,
+        )
+    }
+}
@@ -0,0 +1,88 @@
+//! TLS certificate and private key material used to configure TLS.
+
+use std::fmt;
+
+/// A X509 certificate, usually used as a CA root to verify a TLS peer
+/// against (see
+/// [`ServerTlsConfig::client_ca_root`](crate::transport::server::ServerTlsConfig::client_ca_root)
+/// and
+/// [`ClientTlsConfig::ca_certificate`](crate::transport::channel::ClientTlsConfig::ca_certificate)).
+#[derive(Clone)]
+pub struct Certificate {
+    pub(crate) bytes: CertificateBytes,
+}
+
+#[derive(Clone)]
+pub(crate) enum CertificateBytes {
+    Pem(Vec<u8>),
+    Der(Vec<u8>),
+}
+
+impl Certificate {
+    /// Parse a PEM encoded X509 certificate.
+    pub fn from_pem(pem: impl AsRef<[u8]>) -> Self {
+        Self {
+            bytes: CertificateBytes::Pem(pem.as_ref().to_vec()),
+        }
+    }
+
+    /// Wrap a single DER encoded X509 certificate, skipping PEM parsing
+    /// entirely. Useful for credentials pulled from an HSM, OS keystore, or
+    /// another binary secret store that doesn't speak PEM.
+    pub fn from_der(der: impl AsRef<[u8]>) -> Self {
+        Self {
+            bytes: CertificateBytes::Der(der.as_ref().to_vec()),
+        }
+    }
+}
+
+impl fmt::Debug for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Certificate").finish()
+    }
+}
+
+/// A certificate/private key pair, used to prove a TLS peer's own identity
+/// during the handshake.
+#[derive(Clone)]
+pub struct Identity {
+    pub(crate) bytes: IdentityBytes,
+}
+
+#[derive(Clone)]
+pub(crate) enum IdentityBytes {
+    Pem { cert: Vec<u8>, key: Vec<u8> },
+    Der { cert: Vec<u8>, key: Vec<u8> },
+}
+
+impl Identity {
+    /// Parse a PEM encoded certificate and private key.
+    pub fn from_pem(cert: impl AsRef<[u8]>, key: impl AsRef<[u8]>) -> Self {
+        Self {
+            bytes: IdentityBytes::Pem {
+                cert: cert.as_ref().to_vec(),
+                key: key.as_ref().to_vec(),
+            },
+        }
+    }
+
+    /// Wrap a DER encoded certificate and private key, skipping PEM parsing
+    /// entirely. The key is tried as PKCS#8, then PKCS#1, then SEC1 when the
+    /// identity is loaded. Useful for credentials pulled from an HSM, OS
+    /// keystore, or another binary secret store that doesn't speak PEM.
+    pub fn from_der(cert: impl AsRef<[u8]>, key: impl AsRef<[u8]>) -> Self {
+        Self {
+            bytes: IdentityBytes::Der {
+                cert: cert.as_ref().to_vec(),
+                key: key.as_ref().to_vec(),
+            },
+        }
+    }
+}
+
+impl fmt::Debug for Identity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Deliberately omit `cert`/`key` - this is private key material.
+        f.debug_struct("Identity").finish()
+    }
+}